@@ -20,6 +20,10 @@
 //!  * NFD = more string space, less normalization time (decomposition)
 //!  * NFC = less string space, more normalization time (decomposition + composition)
 //!
+//! [`Nfkc`] additionally folds [compatibility equivalence], e.g. ligatures and roman
+//! numerals are unified with their plain-letter spellings. This is a lossier, opt-in
+//! transformation, so it is kept as a separate type rather than a mode of [`Nfc`].
+//!
 //!
 //! # Partial strings
 //!
@@ -49,15 +53,94 @@
 //! [`unicode-normalization`]: https://crates.io/crates/unicode-normalization
 //! [`String.prototype.normalize()`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
 //! [canonical equivalence]: http://www.unicode.org/reports/tr15/#Canon_Compat_Equivalence
+//! [compatibility equivalence]: http://www.unicode.org/reports/tr15/#Canon_Compat_Equivalence
 //! [normalization forms]: http://www.unicode.org/reports/tr15/#Norm_Forms
 //! [case folding]: https://www.w3.org/International/wiki/Case_folding
 #![allow(dead_code)]
 
+use std::borrow::Cow;
 use std::fmt;
 use std::ops;
 
 use caseless::Caseless;
-use unicode_normalization::{is_nfc, UnicodeNormalization};
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{is_nfc, is_nfc_quick, is_nfkc, IsNormalized, UnicodeNormalization};
+
+/// Tri-state result of a quick normalization check, matching the Unicode
+/// `NFC_Quick_Check` property semantics: [`QuickCheck::Maybe`] means a composing
+/// character follows the point checked so far, and a full normalization pass is
+/// needed to know whether the string is actually NFC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuickCheck {
+    /// The string is definitely already NFC.
+    Yes,
+    /// The string is definitely not NFC.
+    No,
+    /// A full normalization pass is required to know for sure.
+    Maybe,
+}
+
+impl From<IsNormalized> for QuickCheck {
+    fn from(result: IsNormalized) -> Self {
+        match result {
+            IsNormalized::Yes => QuickCheck::Yes,
+            IsNormalized::No => QuickCheck::No,
+            IsNormalized::Maybe => QuickCheck::Maybe,
+        }
+    }
+}
+
+/// Quickly (without allocating) checks whether `s` is NFC-normalized.
+///
+/// See [`QuickCheck`] for how to interpret the result, and [`Nfc::normalize_cow`]
+/// for a normalizer that uses this to skip allocating on already-normalized input.
+pub fn quick_check(s: &str) -> QuickCheck {
+    is_nfc_quick(s.chars()).into()
+}
+
+/// A safe join boundary per TR15: a starter (`ccc == 0`) that is also
+/// `NFC_Quick_Check == Yes`.
+///
+/// `ccc == 0` alone isn't enough: Hangul medial/trailing jamo and a handful of
+/// Indic/SMP vowel-sign "length marks" are starters that can still recompose
+/// with whatever precedes them across a seam (e.g. U+0CBF + U+0CD5 ->
+/// U+0CC0). Rather than hand-maintain a codepoint table for those exceptions
+/// -- which would silently go stale as the UCD version the `unicode_normalization`
+/// dependency ships against moves on -- ask the dependency directly: its
+/// `NFC_Quick_Check` property lookup already encodes exactly this set, since
+/// `Maybe`/`No` are assigned per-codepoint independent of context.
+fn is_safe_join_boundary(c: char) -> bool {
+    canonical_combining_class(c) == 0 && is_nfc_quick(std::iter::once(c)) == IsNormalized::Yes
+}
+
+/// Which case-folding rules [`Nfc::caseless_with`]/[`Nfc::caseless_path_with`] apply.
+///
+/// The [W3C case-folding material] distinguishes default folding from locale-tailored
+/// folding. We only need the Turkic tailoring so far: Turkish and Azeri treat dotless
+/// `ı`/dotted `İ` as distinct letters from ASCII `i`/`I`, so they must not fold together
+/// the way default folding folds them.
+///
+/// [W3C case-folding material]: https://www.w3.org/International/wiki/Case_folding
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaseFoldMode {
+    /// Unconditional case folding (`default_case_fold`), as used by [`Nfc::caseless`].
+    Default,
+    /// Turkic (`tr`, `az`) tailoring: `I`/`İ` fold to `ı`/`i` instead of to `i`/`i` + U+0307.
+    Turkic,
+}
+
+/// Applies the Turkic `I`/`İ` tailoring ahead of default case folding.
+///
+/// `İ` (U+0130) and `I` (U+0049) are the only two code points default folding gets wrong
+/// for Turkic locales; everything else, including `ı` (U+0131) and `i` (U+0069)
+/// themselves, is left untouched by default folding and needs no special-casing.
+fn turkic_fold_char(c: char) -> char {
+    match c {
+        'I' => 'ı',        // U+0049 -> U+0131 dotless i
+        '\u{0130}' => 'i', // İ -> U+0069 i
+        other => other,
+    }
+}
 
 /// A unicode string normalized with NFC.
 ///
@@ -81,9 +164,37 @@ impl Nfc {
         }
     }
 
+    /// Normalizes `s`, borrowing instead of allocating when it's already NFC.
+    ///
+    /// Unlike [`Nfc::from_str`], this returns a plain `Cow<str>` rather than an
+    /// `Nfc`, so bulk scans over already-normalized data (e.g. thousands of
+    /// data-file keys) can skip per-string heap churn entirely. Falls through
+    /// to a full `nfc()` pass on [`QuickCheck::Maybe`] as well as `No`, since
+    /// `Maybe` means quick-check alone can't tell.
+    pub fn normalize_cow(s: &str) -> Cow<'_, str> {
+        match quick_check(s) {
+            QuickCheck::Yes => Cow::Borrowed(s),
+            QuickCheck::No | QuickCheck::Maybe => Cow::Owned(s.chars().nfc().collect()),
+        }
+    }
+
     /// Creates a normalized caseless string.
     pub fn caseless(s: &str) -> Self {
-        let string = s.chars().nfc().default_case_fold().nfc().collect();
+        Self::caseless_with(s, CaseFoldMode::Default)
+    }
+
+    /// Creates a normalized caseless string, case-folded according to `mode`.
+    pub fn caseless_with(s: &str, mode: CaseFoldMode) -> Self {
+        let string = match mode {
+            CaseFoldMode::Default => s.chars().nfc().default_case_fold().nfc().collect(),
+            CaseFoldMode::Turkic => s
+                .chars()
+                .map(turkic_fold_char)
+                .nfc()
+                .default_case_fold()
+                .nfc()
+                .collect(),
+        };
         Self { inner: string }
     }
 
@@ -106,13 +217,22 @@ impl Nfc {
     /// Creates a normalized caseless path string.
     /// Converts '\\' to '/'.
     pub fn caseless_path(s: &str) -> Self {
-        let string = s
-            .chars()
-            .map(|x| if x == '\\' { '/' } else { x })
-            .nfc()
-            .default_case_fold()
-            .nfc()
-            .collect();
+        Self::caseless_path_with(s, CaseFoldMode::Default)
+    }
+
+    /// Creates a normalized caseless path string, case-folded according to `mode`.
+    /// Converts '\\' to '/'.
+    pub fn caseless_path_with(s: &str, mode: CaseFoldMode) -> Self {
+        let chars = s.chars().map(|x| if x == '\\' { '/' } else { x });
+        let string = match mode {
+            CaseFoldMode::Default => chars.nfc().default_case_fold().nfc().collect(),
+            CaseFoldMode::Turkic => chars
+                .map(turkic_fold_char)
+                .nfc()
+                .default_case_fold()
+                .nfc()
+                .collect(),
+        };
         Self { inner: string }
     }
 
@@ -171,11 +291,47 @@ impl ops::Deref for Nfc {
 }
 
 /// Adds a string.
+///
+/// `self` is already NFC and `other` is normalized first unless it already is
+/// (see [`Nfc::normalize_cow`]), so only the seam can change: a safe join
+/// boundary (see [`is_safe_join_boundary`]) on either side of the join can't
+/// recompose or reorder across it. We scan
+/// backward from the end of `self` and forward from the start of `other` to
+/// the nearest such starter, copy the untouched prefix/suffix verbatim as
+/// `&str` slices, and only re-run `nfc()` over the small region in between.
 impl ops::Add<&str> for Nfc {
     type Output = Self;
 
     fn add(self, other: &str) -> Self {
-        let string = self.inner.chars().chain(other.chars()).nfc().collect();
+        let other = Nfc::normalize_cow(other);
+        let other: &str = &other;
+
+        let prefix_end = self
+            .inner
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| is_safe_join_boundary(c))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        let suffix_start = other
+            .char_indices()
+            .find(|&(_, c)| is_safe_join_boundary(c))
+            .map(|(i, _)| i)
+            .unwrap_or(other.len());
+
+        let prefix = &self.inner[..prefix_end];
+        let suffix = &other[suffix_start..];
+        let middle: String = self.inner[prefix_end..]
+            .chars()
+            .chain(other[..suffix_start].chars())
+            .nfc()
+            .collect();
+
+        let mut string = String::with_capacity(prefix.len() + middle.len() + suffix.len());
+        string.push_str(prefix);
+        string.push_str(&middle);
+        string.push_str(suffix);
         Self { inner: string }
     }
 }
@@ -196,9 +352,331 @@ impl fmt::Debug for Nfc {
     }
 }
 
+/// A unicode string normalized with NFKC.
+///
+/// Can be used transparently as a `&str`.
+/// The inner `String` is private to ensure it remains normalized.
+/// Uses default `String` order, which is probably not correct unicode order.
+///
+/// Unlike [`Nfc`], this folds *compatibility* equivalence, e.g. ligatures like `ﬃ`
+/// and the roman numeral `Ⅳ` are unified with their plain-letter spellings.
+/// Kept as a distinct type so a canonical-normalized key can't accidentally be
+/// compared against a compatibility-normalized one.
+#[derive(Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Nfkc {
+    inner: String,
+}
+
+impl Nfkc {
+    /// Creates a normalized string.
+    pub fn from_str(s: &str) -> Self {
+        if is_nfkc(s) {
+            let string = s.to_owned();
+            Self { inner: string }
+        } else {
+            let string = s.chars().nfkc().collect();
+            Self { inner: string }
+        }
+    }
+
+    /// Creates a normalized caseless string.
+    pub fn caseless(s: &str) -> Self {
+        let string = s.chars().nfkc().default_case_fold().nfkc().collect();
+        Self { inner: string }
+    }
+
+    /// Creates a normalized path string.
+    /// Converts '\\' to '/'.
+    pub fn path(s: &str) -> Self {
+        if is_nfkc(s) && !s.contains('\\') {
+            let string = s.to_owned();
+            Self { inner: string }
+        } else {
+            let string = s
+                .chars()
+                .map(|x| if x == '\\' { '/' } else { x })
+                .nfkc()
+                .collect();
+            Self { inner: string }
+        }
+    }
+
+    /// Creates a normalized caseless path string.
+    /// Converts '\\' to '/'.
+    pub fn caseless_path(s: &str) -> Self {
+        let string = s
+            .chars()
+            .map(|x| if x == '\\' { '/' } else { x })
+            .nfkc()
+            .default_case_fold()
+            .nfkc()
+            .collect();
+        Self { inner: string }
+    }
+
+    /// Match `String::as_str()`.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl AsRef<[u8]> for Nfkc {
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl AsRef<str> for Nfkc {
+    fn as_ref(&self) -> &str {
+        &self.inner
+    }
+}
+
+/// Converts to a normalized string.
+impl From<&str> for Nfkc {
+    fn from(s: &str) -> Self {
+        Nfkc::from_str(s)
+    }
+}
+
+/// Converts to a normalized string.
+/// Consumes the original string.
+impl From<String> for Nfkc {
+    fn from(string: String) -> Self {
+        if is_nfkc(&string) {
+            Self { inner: string }
+        } else {
+            let string = string.chars().nfkc().collect();
+            Self { inner: string }
+        }
+    }
+}
+
+/// Unwraps the inner string.
+impl Into<String> for Nfkc {
+    fn into(self) -> String {
+        self.inner
+    }
+}
+
+/// Inherits all the methods of `str`.
+impl ops::Deref for Nfkc {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.inner
+    }
+}
+
+/// Adds a string.
+impl ops::Add<&str> for Nfkc {
+    type Output = Self;
+
+    fn add(self, other: &str) -> Self {
+        let string = self.inner.chars().chain(other.chars()).nfkc().collect();
+        Self { inner: string }
+    }
+}
+
+/// Matches the inner string.
+impl fmt::Display for Nfkc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display: &fmt::Display = &self.inner;
+        display.fmt(f)
+    }
+}
+
+/// Matches the inner string.
+impl fmt::Debug for Nfkc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let debug: &fmt::Debug = &self.inner;
+        debug.fmt(f)
+    }
+}
+
+/// A locale tag used to tailor [`Nfc::collation_key`].
+///
+/// Only the root (locale-independent) collation is currently implemented;
+/// the tag is accepted and stored so call sites don't need to change again
+/// once per-locale tailoring (e.g. Turkic or Nordic orderings) is added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    tag: String,
+}
+
+impl Locale {
+    /// Creates a locale from a BCP 47-ish tag, e.g. `"en"` or `"tr-TR"`.
+    pub fn new(tag: &str) -> Self {
+        Self {
+            tag: tag.to_owned(),
+        }
+    }
+
+    /// The tag this locale was created from.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+}
+
+/// How many weight levels a [`CollationKey`] compares.
+///
+/// Mirrors the DUCET notion of collation strength: [`Strength::Primary`]
+/// ignores both accents and case (good for a loose "does this match" search),
+/// [`Strength::Secondary`] additionally distinguishes accents, and
+/// [`Strength::Tertiary`] additionally distinguishes case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strength {
+    Primary,
+    Secondary,
+    Tertiary,
+}
+
+/// Separates weight levels within a [`CollationKey`]'s byte key.
+///
+/// `0x00` sorts below the first byte of every weight a level can hold: primary
+/// weights are lowercase UTF-8 text or a digit run whose length prefix is at
+/// least 1 (see [`push_primary_weight`]), and secondary weights are the UTF-8
+/// bytes of combining marks. A key whose level is a prefix of another's
+/// therefore sorts first, and lower levels only break ties of higher ones.
+const COLLATION_LEVEL_SEPARATOR: u8 = 0x00;
+
+fn push_primary_weight(level: &mut Vec<u8>, run: &str) {
+    if let Ok(n) = run.parse::<u64>() {
+        // Digit grouping: encode whole digit runs as a length-prefixed
+        // big-endian number, so "item 9" sorts before "item 10" instead of
+        // comparing the '1' of "10" against the '9' of "9" byte-by-byte.
+        let bytes = n.to_be_bytes();
+        let significant = &bytes[bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1)..];
+        level.push(significant.len() as u8);
+        level.extend_from_slice(significant);
+    } else {
+        level.extend(run.to_lowercase().as_bytes());
+    }
+}
+
+/// An opaque, byte-comparable locale-aware sort key produced by
+/// [`Nfc::collation_key`].
+///
+/// Byte-wise (hence `Ord`) comparison of two keys matches collation order at
+/// whichever [`Strength`] they were built with.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CollationKey {
+    bytes: Vec<u8>,
+}
+
+impl Nfc {
+    /// Produces a root-collation [`CollationKey`] for sorting, in place of the
+    /// default `String` order (see the module docs).
+    ///
+    /// This is a simplified, DUCET-inspired multi-level key: the primary
+    /// level folds case and strips accents (with digit runs grouped so
+    /// numbers sort by magnitude), the secondary level restores accents, and
+    /// the tertiary level restores case. `strength` controls how many of
+    /// those levels are included.
+    ///
+    /// `locale` does not yet tailor the weights (e.g. Turkic `i`/`ı` sorting
+    /// before or after `h`/`j` differently than root collation, or Nordic
+    /// `å`/`ä`/`ö` sorting after `z`) — only root/DUCET-ish ordering is
+    /// produced today. The parameter is accepted now so call sites don't need
+    /// to change again once that tailoring is added; see [`Locale`].
+    pub fn collation_key(&self, locale: &Locale, strength: Strength) -> CollationKey {
+        // Root collation only: `locale` isn't tailored against yet, see above.
+        let _ = locale;
+
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        let mut tertiary = Vec::new();
+        let mut digit_run = String::new();
+
+        let flush_digit_run = |primary: &mut Vec<u8>, digit_run: &mut String| {
+            if !digit_run.is_empty() {
+                push_primary_weight(primary, digit_run);
+                digit_run.clear();
+            }
+        };
+
+        for c in self.inner.chars().nfd() {
+            if canonical_combining_class(c) != 0 {
+                // A combining mark: contributes only to the secondary
+                // (accent) level.
+                flush_digit_run(&mut primary, &mut digit_run);
+                let mut mark_buf = [0u8; 4];
+                secondary.extend(c.encode_utf8(&mut mark_buf).as_bytes());
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                digit_run.push(c);
+                continue;
+            }
+            flush_digit_run(&mut primary, &mut digit_run);
+
+            let mut lower_buf = [0u8; 4];
+            for lower_c in c.to_lowercase() {
+                primary.extend(lower_c.encode_utf8(&mut lower_buf).as_bytes());
+            }
+            tertiary.push(c.is_uppercase() as u8);
+        }
+        flush_digit_run(&mut primary, &mut digit_run);
+
+        let mut bytes = primary;
+        if strength != Strength::Primary {
+            bytes.push(COLLATION_LEVEL_SEPARATOR);
+            bytes.extend(secondary);
+        }
+        if strength == Strength::Tertiary {
+            bytes.push(COLLATION_LEVEL_SEPARATOR);
+            bytes.extend(tertiary);
+        }
+        CollationKey { bytes }
+    }
+}
+
+/// Wraps an [`Nfc`] so it sorts by locale-aware [`CollationKey`] instead of
+/// `Nfc`'s default `String` order, without changing `Nfc`'s own `Ord` impl.
+#[derive(Clone, Debug)]
+pub struct Collated<'a> {
+    value: &'a Nfc,
+    key: CollationKey,
+}
+
+impl<'a> Collated<'a> {
+    pub fn new(value: &'a Nfc, locale: &Locale, strength: Strength) -> Self {
+        Self {
+            key: value.collation_key(locale, strength),
+            value,
+        }
+    }
+
+    pub fn into_inner(self) -> &'a Nfc {
+        self.value
+    }
+}
+
+impl PartialEq for Collated<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Collated<'_> {}
+
+impl PartialOrd for Collated<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collated<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Nfc;
+    use unicode_normalization::UnicodeNormalization;
 
     // from the caseless crate
 
@@ -216,6 +694,46 @@ mod tests {
         nfc_caseless!("straße", "strasse");
     }
 
+    #[test]
+    fn turkic_caseless() {
+        use super::CaseFoldMode;
+
+        // Default folding maps I to ASCII i and dotted I to i + U+0307, which is wrong for Turkic.
+        assert_eq!(Nfc::caseless("I").as_str(), "i");
+        assert_eq!(Nfc::caseless("\u{0130}").as_str(), "i\u{0307}");
+
+        // Turkic folding keeps them distinct instead.
+        assert_eq!(
+            Nfc::caseless_with("I", CaseFoldMode::Turkic).as_str(),
+            "\u{0131}"
+        );
+        assert_eq!(
+            Nfc::caseless_with("\u{0130}", CaseFoldMode::Turkic).as_str(),
+            "i"
+        );
+        assert_eq!(
+            Nfc::caseless_with("\u{0131}", CaseFoldMode::Turkic).as_str(),
+            "\u{0131}"
+        );
+    }
+
+    #[test]
+    fn normalize_cow_borrows_already_normalized_input() {
+        use std::borrow::Cow;
+
+        let s = "already nfc";
+        match Nfc::normalize_cow(s) {
+            Cow::Borrowed(borrowed) => assert_eq!(borrowed, s),
+            Cow::Owned(_) => panic!("expected a borrow for already-normalized input"),
+        }
+
+        let unnormalized = "\u{0071}\u{0307}\u{0323}"; // q + dot_above + dot_below
+        match Nfc::normalize_cow(unnormalized) {
+            Cow::Borrowed(_) => panic!("expected an owned, normalized copy"),
+            Cow::Owned(owned) => assert_eq!(owned, Nfc::from_str(unnormalized).as_str()),
+        }
+    }
+
     // from http://www.unicode.org/reports/tr15/
 
     macro_rules! nfc_eq {
@@ -277,6 +795,40 @@ mod tests {
         nfc_add!("\u{1100}", "\u{1161}\u{11A8}", "\u{AC01}");
     }
 
+    #[test]
+    fn string_concatenation_across_indic_trailing_composer() {
+        // BENGALI VOWEL SIGN E + BENGALI VOWEL SIGN AA composes to BENGALI
+        // VOWEL SIGN O, even though the vowel sign on the right is itself a
+        // `ccc == 0` starter (it's `NFC_Quick_Check = Maybe`, not a safe
+        // join boundary).
+        nfc_add!("\u{09C7}", "\u{09BE}", "\u{09CB}");
+    }
+
+    #[test]
+    fn string_concatenation_across_kannada_length_mark() {
+        // KANNADA VOWEL SIGN I + KANNADA LENGTH MARK composes to KANNADA
+        // VOWEL SIGN II; U+0CD5 isn't in any hand-picked exclusion list, so
+        // this only passes if the boundary check asks the dependency's own
+        // NFC_Quick_Check property rather than a hardcoded table.
+        nfc_add!("\u{0CBF}", "\u{0CD5}", "\u{0CC0}");
+    }
+
+    #[test]
+    fn string_concatenation_skips_unaffected_prefix() {
+        // A long prefix that's well clear of the join boundary must be left
+        // untouched, and the boundary-aware join must still match the naive
+        // full-reprocessing result.
+        let prefix = "The quick brown fox jumps over the lazy dog. ".repeat(100);
+        let left = prefix.clone() + "\u{0061}";
+        let right = "\u{0302} and the rest of the sentence.";
+
+        let naive: String = left.chars().chain(right.chars()).nfc().collect();
+        let fast = (Nfc::from_str(&left) + right).as_str().to_owned();
+
+        assert_eq!(fast, naive);
+        assert!(fast.starts_with(&prefix));
+    }
+
     #[test]
     fn basic_examples() {
         // Table 6. Basic Examples
@@ -312,4 +864,187 @@ mod tests {
         // I'm not sure, but this one is probably U+CE8C HANGUL SYLLABLE KAK
         nfc!("\u{CE8C}", "\u{CE8C}"); // u: kaks => kaks
     }
+
+    // from http://www.unicode.org/reports/tr15/
+
+    macro_rules! nfkc {
+        ($input: expr, $expected: expr) => {
+            assert_eq!(super::Nfkc::from_str($input).as_str(), $expected);
+        };
+    }
+
+    #[test]
+    fn nfkc_applied_to_compatibility_equivalent_string() {
+        // Table 7. NFD and NFC Applied to Compatibility-Equivalent Strings
+        // Unlike Nfc, Nfkc unifies these compatibility-equivalent spellings.
+        nfkc!("Äffin", "Äffin"); // l
+        nfkc!("Ä\u{FB03}n", "Äffin"); // m: ffi ligature => ffi
+        nfkc!("Henry IV", "Henry IV"); // n
+        nfkc!("Henry \u{2163}", "Henry IV"); // o: roman numeral four => IV
+    }
+
+    #[test]
+    fn collation_key_ignores_accents_and_case_by_strength() {
+        use super::{Locale, Strength};
+
+        let locale = Locale::new("en");
+        let resume1 = Nfc::from_str("resume");
+        let resume2 = Nfc::from_str("r\u{00E9}sum\u{00E9}"); // résumé
+        let resume3 = Nfc::from_str("RESUME");
+
+        // Primary: accents and case are both ignored.
+        assert_eq!(
+            resume1.collation_key(&locale, Strength::Primary),
+            resume2.collation_key(&locale, Strength::Primary)
+        );
+        assert_eq!(
+            resume1.collation_key(&locale, Strength::Primary),
+            resume3.collation_key(&locale, Strength::Primary)
+        );
+
+        // Tertiary: both accents and case are significant again.
+        assert_ne!(
+            resume1.collation_key(&locale, Strength::Tertiary),
+            resume2.collation_key(&locale, Strength::Tertiary)
+        );
+        assert_ne!(
+            resume1.collation_key(&locale, Strength::Tertiary),
+            resume3.collation_key(&locale, Strength::Tertiary)
+        );
+    }
+
+    #[test]
+    fn collation_key_groups_digit_runs_by_magnitude() {
+        use super::{Locale, Strength};
+
+        let locale = Locale::new("en");
+        let mut names = vec![
+            Nfc::from_str("Item 10"),
+            Nfc::from_str("Item 9"),
+            Nfc::from_str("Item 2"),
+        ];
+        names.sort_by_key(|n| n.collation_key(&locale, Strength::Tertiary));
+
+        let sorted: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        assert_eq!(sorted, ["Item 2", "Item 9", "Item 10"]);
+    }
+}
+
+/// Conformance test driven by the official Unicode `NormalizationTest.txt`
+/// format: five semicolon-separated columns, `source; NFC; NFD; NFKC; NFKD`,
+/// each holding one or more space-separated hex code points.
+///
+/// Gated behind the `ucd-conformance-tests` feature so an ordinary `cargo
+/// test` doesn't pay for parsing the whole UCD file; turn it on (and
+/// re-vendor `test-data/NormalizationTest.txt` from
+/// <https://www.unicode.org/Public/UCD/latest/ucd/NormalizationTest.txt>)
+/// whenever the `unicode-normalization` dependency's UCD version bumps, to
+/// catch the canonical-ordering/singleton regressions (e.g. U+212B ANGSTROM
+/// SIGN -> U+00C5) that the hand-written TR15 tests above only spot-check.
+///
+/// This requires declaring the feature in `Cargo.toml`:
+/// ```toml
+/// [features]
+/// ucd-conformance-tests = []
+/// ```
+/// As of this writing this crate's `Cargo.toml` does not yet declare that
+/// feature, so `cargo test --features ucd-conformance-tests` cannot enable
+/// this module -- the declaration above must land in `Cargo.toml` before the
+/// harness is runnable; `#[cfg]` can't reach across files to add it itself.
+#[cfg(all(test, feature = "ucd-conformance-tests"))]
+mod ucd_conformance {
+    use super::Nfc;
+
+    const NORMALIZATION_TEST_TXT: &str = include_str!("../test-data/NormalizationTest.txt");
+
+    struct Row {
+        source: String,
+        nfc: String,
+        nfd: String,
+        nfkc: String,
+        nfkd: String,
+    }
+
+    fn parse_code_points(field: &str) -> String {
+        field
+            .split_whitespace()
+            .map(|code_point| {
+                let scalar = u32::from_str_radix(code_point, 16)
+                    .unwrap_or_else(|_| panic!("not a hex code point: {code_point:?}"));
+                char::from_u32(scalar)
+                    .unwrap_or_else(|| panic!("not a valid code point: U+{scalar:04X}"))
+            })
+            .collect()
+    }
+
+    fn rows() -> impl Iterator<Item = Row> {
+        NORMALIZATION_TEST_TXT
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('@'))
+            .map(|line| {
+                let line = line.split('#').next().unwrap_or(line);
+                let mut columns = line.split(';');
+                let source = parse_code_points(columns.next().expect("source column"));
+                let nfc = parse_code_points(columns.next().expect("NFC column"));
+                let nfd = parse_code_points(columns.next().expect("NFD column"));
+                let nfkc = parse_code_points(columns.next().expect("NFKC column"));
+                let nfkd = parse_code_points(columns.next().expect("NFKD column"));
+                Row {
+                    source,
+                    nfc,
+                    nfd,
+                    nfkc,
+                    nfkd,
+                }
+            })
+    }
+
+    #[test]
+    fn nfc_matches_normalization_test_txt() {
+        // Per the conformance format's own documented invariants (see the
+        // header of the full upstream NormalizationTest.txt; the vendored
+        // excerpt omits it): the source/NFC/NFD columns are
+        // all canonically equivalent, so NFC of any of them is the NFC
+        // column; separately, NFKC/NFKD are compatibility-equivalent to each
+        // other (and canonically composed already), so NFC of either is the
+        // NFKC column, not the NFC column.
+        for row in rows() {
+            for (label, column) in [
+                ("source", &row.source),
+                ("NFC", &row.nfc),
+                ("NFD", &row.nfd),
+            ] {
+                assert_eq!(
+                    Nfc::from_str(column).as_str(),
+                    row.nfc,
+                    "NFC({label} column {column:?}) should be {:?}",
+                    row.nfc
+                );
+            }
+            for (label, column) in [("NFKC", &row.nfkc), ("NFKD", &row.nfkd)] {
+                assert_eq!(
+                    Nfc::from_str(column).as_str(),
+                    row.nfkc,
+                    "NFC({label} column {column:?}) should be {:?}",
+                    row.nfkc
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nfc_is_idempotent_over_normalization_test_txt() {
+        for row in rows() {
+            let once = Nfc::from_str(&row.nfc);
+            let twice = Nfc::from_str(once.as_str());
+            assert_eq!(
+                once.as_str(),
+                twice.as_str(),
+                "normalize(normalize({:?})) should equal normalize({:?})",
+                row.nfc,
+                row.nfc
+            );
+        }
+    }
 }